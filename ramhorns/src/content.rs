@@ -12,8 +12,10 @@ use crate::{Section, Template};
 use crate::traits::{ContentSequence};
 
 use std::borrow::{Borrow, Cow, ToOwned};
+use std::cell::{Cell, RefCell};
 use std::collections::{BTreeMap, HashMap};
 use std::hash::{BuildHasher, Hash};
+use std::iter::Peekable;
 use std::ops::Deref;
 
 /// Trait allowing the rendering to quickly access data stored in the type that
@@ -228,7 +230,7 @@ impl Content for bool {
     }
 }
 
-macro_rules! impl_number_types {
+macro_rules! impl_unsigned_int_types {
     ($( $ty:ty ),*) => {
         $(
             impl Content for $ty {
@@ -239,7 +241,8 @@ macro_rules! impl_number_types {
 
                 #[inline]
                 fn capacity_hint(&self, _tpl: &Template) -> usize {
-                    5
+                    // Number of decimal digits, e.g. `0` -> 1, `255` -> 3
+                    self.checked_ilog10().unwrap_or(0) as usize + 1
                 }
 
                 #[inline]
@@ -253,7 +256,36 @@ macro_rules! impl_number_types {
     }
 }
 
-impl_number_types!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+macro_rules! impl_signed_int_types {
+    ($( $ty:ty ),*) => {
+        $(
+            impl Content for $ty {
+                #[inline]
+                fn is_truthy(&self) -> bool {
+                    *self != 0 as $ty
+                }
+
+                #[inline]
+                fn capacity_hint(&self, _tpl: &Template) -> usize {
+                    // Decimal digits of the magnitude, plus one for a `-` sign
+                    self.unsigned_abs().checked_ilog10().unwrap_or(0) as usize
+                        + 1
+                        + (*self < 0) as usize
+                }
+
+                #[inline]
+                fn render_escaped<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error>
+                {
+                    // Nothing to escape here
+                    encoder.format_unescaped(self)
+                }
+            }
+        )*
+    }
+}
+
+impl_unsigned_int_types!(u8, u16, u32, u64, u128, usize);
+impl_signed_int_types!(i8, i16, i32, i64, i128, isize);
 
 impl Content for f32 {
     #[inline]
@@ -264,7 +296,9 @@ impl Content for f32 {
 
     #[inline]
     fn capacity_hint(&self, _tpl: &Template) -> usize {
-        5
+        // Best-effort hint sized for typical values, not a strict upper bound:
+        // a full decimal expansion of an extreme `f32` can run past 40 digits.
+        16
     }
 
     #[inline]
@@ -283,7 +317,9 @@ impl Content for f64 {
 
     #[inline]
     fn capacity_hint(&self, _tpl: &Template) -> usize {
-        5
+        // Best-effort hint sized for typical values, not a strict upper bound:
+        // a full decimal expansion of an extreme `f64` can run past 300 digits.
+        24
     }
 
     #[inline]
@@ -441,6 +477,175 @@ impl<T: Content> Content for &[T] {
     }
 }
 
+/// Wraps any `IntoIterator` so it can be rendered as a section without first
+/// collecting it into a `Vec`, letting large or generated sequences (database
+/// cursors, line iterators, ...) stream straight into a template.
+///
+/// The wrapped iterator is consumed exactly once, the first time the section
+/// is rendered. Whether it was truthy (had at least one item) is cached on
+/// that first check, so a subsequent `{{^section}}` inverse keys off the
+/// iterator's original contents rather than its now-drained state.
+pub struct Iter<I: IntoIterator> {
+    iter: RefCell<Option<Peekable<I::IntoIter>>>,
+    truthy: Cell<Option<bool>>,
+}
+
+impl<I: IntoIterator> Iter<I> {
+    /// Wrap an `IntoIterator` for streaming section rendering.
+    #[inline]
+    pub fn new(iter: I) -> Self {
+        Iter {
+            iter: RefCell::new(Some(iter.into_iter().peekable())),
+            truthy: Cell::new(None),
+        }
+    }
+}
+
+impl<I> Content for Iter<I>
+where
+    I: IntoIterator,
+    I::Item: Content,
+{
+    #[inline]
+    fn is_truthy(&self) -> bool {
+        if let Some(truthy) = self.truthy.get() {
+            return truthy;
+        }
+
+        let truthy = self
+            .iter
+            .borrow_mut()
+            .as_mut()
+            .map_or(false, |iter| iter.peek().is_some());
+
+        self.truthy.set(Some(truthy));
+        truthy
+    }
+
+    fn render_section<P, E>(
+        &self,
+        section: Section<P>,
+        encoder: &mut E,
+    ) -> Result<(), E::Error>
+    where
+        P: ContentSequence,
+        E: Encoder,
+    {
+        // Caches truthiness before the iterator is drained, so `render_inverse`
+        // below still sees whether it originally had any items.
+        self.is_truthy();
+
+        let taken = self.iter.borrow_mut().take();
+
+        if let Some(iter) = taken {
+            for item in iter {
+                section.render_once(&item, encoder)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Renders the inverse section based on the iterator's original
+    /// truthiness, not whether it has since been drained by `render_section`,
+    /// so `{{#items}}...{{/items}}{{^items}}...{{/items}}` doesn't fire both
+    /// branches for a non-empty iterator.
+    fn render_inverse<P, E>(
+        &self,
+        section: Section<P>,
+        encoder: &mut E,
+    ) -> Result<(), E::Error>
+    where
+        P: ContentSequence,
+        E: Encoder,
+    {
+        if !self.is_truthy() {
+            section.render_once(self, encoder)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A single key/value pair borrowed from a map, exposed to templates as a
+/// `Content` with the field names `key` and `value`. Backs the `render_section`
+/// impls on `HashMap`/`BTreeMap` below, driving the `{{#map}}{{key}}={{value}}{{/map}}`
+/// pattern.
+///
+/// Only the `key`/`value` field names are supported; `@key`/`.` are not
+/// handled, as nothing else in this crate gives field-less `Content` impls a
+/// meaning for those forms.
+struct Entry<'a, V> {
+    key: &'a str,
+    value: &'a V,
+}
+
+impl<'a, V: Content> Content for Entry<'a, V> {
+    #[inline]
+    fn render_field_escaped<E: Encoder>(
+        &self,
+        _hash: u64,
+        name: &str,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error> {
+        match name {
+            "key" => encoder.write_escaped(self.key).map(|_| true),
+            "value" => self.value.render_escaped(encoder).map(|_| true),
+            _ => Ok(false),
+        }
+    }
+
+    #[inline]
+    fn render_field_unescaped<E: Encoder>(
+        &self,
+        _hash: u64,
+        name: &str,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error> {
+        match name {
+            "key" => encoder.write_unescaped(self.key).map(|_| true),
+            "value" => self.value.render_unescaped(encoder).map(|_| true),
+            _ => Ok(false),
+        }
+    }
+
+    #[inline]
+    fn render_field_section<P, E>(
+        &self,
+        _hash: u64,
+        name: &str,
+        section: Section<P>,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error>
+    where
+        P: ContentSequence,
+        E: Encoder,
+    {
+        match name {
+            "value" => self.value.render_section(section, encoder).map(|_| true),
+            _ => Ok(false),
+        }
+    }
+
+    #[inline]
+    fn render_field_inverse<P, E>(
+        &self,
+        _hash: u64,
+        name: &str,
+        section: Section<P>,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error>
+    where
+        P: ContentSequence,
+        E: Encoder,
+    {
+        match name {
+            "value" => self.value.render_inverse(section, encoder).map(|_| true),
+            _ => Ok(false),
+        }
+    }
+}
+
 impl<K, V, S> Content for HashMap<K, V, S>
 where
     K: Borrow<str> + Hash + Eq,
@@ -451,6 +656,32 @@ where
         !self.is_empty()
     }
 
+    /// Renders the map as a section, once per key/value pair, so templates
+    /// can do `{{#map}}{{key}}={{value}}{{/map}}`.
+    ///
+    /// Breaking change: before this impl existed, `{{#map}}...{{/map}}`
+    /// fell through to the default `render_section`, which pushes the map
+    /// itself as the section's context, so a body could resolve the map's
+    /// own fields directly (e.g. `{{#settings}}{{existing_key}}{{/settings}}`).
+    /// That is no longer possible: the section body is now evaluated once
+    /// per entry against a `key`/`value`-only `Entry`, so `{{existing_key}}`
+    /// resolves to nothing inside the section.
+    fn render_section<P, E>(
+        &self,
+        section: Section<P>,
+        encoder: &mut E,
+    ) -> Result<(), E::Error>
+    where
+        P: ContentSequence,
+        E: Encoder,
+    {
+        for (key, value) in self.iter() {
+            section.render_once(&Entry { key: key.borrow(), value }, encoder)?;
+        }
+
+        Ok(())
+    }
+
     fn render_field_escaped<E>(&self, _: u64, name: &str, encoder: &mut E) -> Result<bool, E::Error>
     where
         E: Encoder,
@@ -520,6 +751,32 @@ where
         !self.is_empty()
     }
 
+    /// Renders the map as a section, once per key/value pair, so templates
+    /// can do `{{#map}}{{key}}={{value}}{{/map}}`.
+    ///
+    /// Breaking change: before this impl existed, `{{#map}}...{{/map}}`
+    /// fell through to the default `render_section`, which pushes the map
+    /// itself as the section's context, so a body could resolve the map's
+    /// own fields directly (e.g. `{{#settings}}{{existing_key}}{{/settings}}`).
+    /// That is no longer possible: the section body is now evaluated once
+    /// per entry against a `key`/`value`-only `Entry`, so `{{existing_key}}`
+    /// resolves to nothing inside the section.
+    fn render_section<P, E>(
+        &self,
+        section: Section<P>,
+        encoder: &mut E,
+    ) -> Result<(), E::Error>
+    where
+        P: ContentSequence,
+        E: Encoder,
+    {
+        for (key, value) in self.iter() {
+            section.render_once(&Entry { key: key.borrow(), value }, encoder)?;
+        }
+
+        Ok(())
+    }
+
     fn render_field_escaped<E>(&self, _: u64, name: &str, encoder: &mut E) -> Result<bool, E::Error>
     where
         E: Encoder,
@@ -580,6 +837,135 @@ where
     }
 }
 
+/// Renders a dynamically typed JSON value, looking up fields of a
+/// `Value::Object` by name and treating a `Value::Array` as a section
+/// sequence, same as the `Vec<T>` impl above.
+#[cfg(feature = "serde_json")]
+impl Content for serde_json::Value {
+    #[inline]
+    fn is_truthy(&self) -> bool {
+        match self {
+            serde_json::Value::Null => false,
+            serde_json::Value::Bool(b) => *b,
+            serde_json::Value::Number(n) => n.as_f64().map_or(true, |n| n != 0.0),
+            serde_json::Value::String(s) => !s.is_empty(),
+            serde_json::Value::Array(a) => !a.is_empty(),
+            serde_json::Value::Object(o) => !o.is_empty(),
+        }
+    }
+
+    #[inline]
+    fn capacity_hint(&self, tpl: &Template) -> usize {
+        match self {
+            serde_json::Value::Null => 0,
+            serde_json::Value::Bool(_) => 5,
+            serde_json::Value::Number(_) => 20,
+            serde_json::Value::String(s) => s.len(),
+            serde_json::Value::Array(a) => a.iter().map(|v| v.capacity_hint(tpl)).sum(),
+            serde_json::Value::Object(o) => o.values().map(|v| v.capacity_hint(tpl)).sum(),
+        }
+    }
+
+    #[inline]
+    fn render_escaped<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error> {
+        match self {
+            serde_json::Value::String(s) => encoder.write_escaped(s),
+            serde_json::Value::Null => Ok(()),
+            serde_json::Value::Bool(b) => encoder.write_unescaped(if *b { "true" } else { "false" }),
+            serde_json::Value::Number(n) => encoder.write_unescaped(&n.to_string()),
+            serde_json::Value::Array(_) | serde_json::Value::Object(_) => Ok(()),
+        }
+    }
+
+    #[inline]
+    fn render_unescaped<E: Encoder>(&self, encoder: &mut E) -> Result<(), E::Error> {
+        match self {
+            serde_json::Value::String(s) => encoder.write_unescaped(s),
+            _ => self.render_escaped(encoder),
+        }
+    }
+
+    fn render_field_escaped<E: Encoder>(
+        &self,
+        _hash: u64,
+        name: &str,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error> {
+        match self.as_object().and_then(|obj| obj.get(name)) {
+            Some(v) => v.render_escaped(encoder).map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    fn render_field_unescaped<E: Encoder>(
+        &self,
+        _hash: u64,
+        name: &str,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error> {
+        match self.as_object().and_then(|obj| obj.get(name)) {
+            Some(v) => v.render_unescaped(encoder).map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    fn render_field_section<P, E>(
+        &self,
+        _hash: u64,
+        name: &str,
+        section: Section<P>,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error>
+    where
+        P: ContentSequence,
+        E: Encoder,
+    {
+        match self.as_object().and_then(|obj| obj.get(name)) {
+            Some(v) => v.render_section(section, encoder).map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    fn render_field_inverse<P, E>(
+        &self,
+        _hash: u64,
+        name: &str,
+        section: Section<P>,
+        encoder: &mut E,
+    ) -> Result<bool, E::Error>
+    where
+        P: ContentSequence,
+        E: Encoder,
+    {
+        match self.as_object().and_then(|obj| obj.get(name)) {
+            Some(v) => v.render_inverse(section, encoder).map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    fn render_section<P, E>(
+        &self,
+        section: Section<P>,
+        encoder: &mut E,
+    ) -> Result<(), E::Error>
+    where
+        P: ContentSequence,
+        E: Encoder,
+    {
+        match self {
+            serde_json::Value::Array(items) => {
+                for item in items.iter() {
+                    section.render_once(item, encoder)?;
+                }
+
+                Ok(())
+            }
+            _ if self.is_truthy() => section.render_once(self, encoder),
+            _ => Ok(()),
+        }
+    }
+}
+
 macro_rules! impl_pointer_types {
     ($( $ty:ty $(: $bounds:tt)? ),*) => {
         $(
@@ -0,0 +1,20 @@
+use ramhorns::{Content, Template};
+
+#[test]
+fn unsigned_capacity_hint_matches_digit_count() {
+    let tpl = Template::new("").unwrap();
+
+    assert_eq!(0u32.capacity_hint(&tpl), 1);
+    assert_eq!(9u32.capacity_hint(&tpl), 1);
+    assert_eq!(255u8.capacity_hint(&tpl), 3);
+    assert_eq!(u128::MAX.capacity_hint(&tpl), 39);
+}
+
+#[test]
+fn signed_capacity_hint_accounts_for_sign() {
+    let tpl = Template::new("").unwrap();
+
+    assert_eq!(5i32.capacity_hint(&tpl), 1);
+    assert_eq!((-5i32).capacity_hint(&tpl), 2);
+    assert_eq!(i128::MIN.capacity_hint(&tpl), 40);
+}
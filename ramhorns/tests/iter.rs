@@ -0,0 +1,49 @@
+use ramhorns::{Content, Iter, Template};
+
+#[derive(Content)]
+struct Listing<'a> {
+    items: Iter<Vec<&'a str>>,
+}
+
+#[test]
+fn streams_each_item_once() {
+    let tpl = Template::new("{{#items}}{{.}};{{/items}}").unwrap();
+    let data = Listing {
+        items: Iter::new(vec!["a", "b", "c"]),
+    };
+
+    assert_eq!(tpl.render(&data), "a;b;c;");
+}
+
+#[test]
+fn empty_iterator_is_not_truthy() {
+    let tpl = Template::new("{{#items}}x{{/items}}{{^items}}empty{{/items}}").unwrap();
+    let data = Listing {
+        items: Iter::new(Vec::new()),
+    };
+
+    assert_eq!(tpl.render(&data), "empty");
+}
+
+#[test]
+fn non_empty_section_does_not_also_fire_inverse() {
+    let tpl = Template::new("{{#items}}{{.}};{{/items}}{{^items}}none{{/items}}").unwrap();
+    let data = Listing {
+        items: Iter::new(vec!["a", "b"]),
+    };
+
+    assert_eq!(tpl.render(&data), "a;b;");
+}
+
+#[test]
+fn rendering_twice_only_streams_once() {
+    let tpl = Template::new("{{#items}}{{.}};{{/items}}").unwrap();
+    let data = Listing {
+        items: Iter::new(vec!["a", "b"]),
+    };
+
+    assert_eq!(tpl.render(&data), "a;b;");
+    // The wrapped iterator is consumed by the first render, so a second
+    // render sees an empty section.
+    assert_eq!(tpl.render(&data), "");
+}
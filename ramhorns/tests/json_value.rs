@@ -0,0 +1,40 @@
+#![cfg(feature = "serde_json")]
+
+use ramhorns::Template;
+use serde_json::json;
+
+#[test]
+fn renders_object_fields() {
+    let tpl = Template::new("{{name}} is {{age}}").unwrap();
+    let data = json!({ "name": "Ferris", "age": 10 });
+
+    assert_eq!(tpl.render(&data), "Ferris is 10");
+}
+
+#[test]
+fn renders_array_as_section() {
+    let tpl = Template::new("{{#items}}{{name}};{{/items}}").unwrap();
+    let data = json!({ "items": [{ "name": "a" }, { "name": "b" }] });
+
+    assert_eq!(tpl.render(&data), "a;b;");
+}
+
+#[test]
+fn truthiness_controls_sections_and_inverses() {
+    let tpl = Template::new("{{#flag}}yes{{/flag}}{{^flag}}no{{/flag}}").unwrap();
+
+    assert_eq!(tpl.render(&json!({ "flag": true })), "yes");
+    assert_eq!(tpl.render(&json!({ "flag": false })), "no");
+    assert_eq!(tpl.render(&json!({ "flag": null })), "no");
+    assert_eq!(tpl.render(&json!({ "flag": "" })), "no");
+    assert_eq!(tpl.render(&json!({ "flag": 0 })), "no");
+    assert_eq!(tpl.render(&json!({})), "no");
+}
+
+#[test]
+fn missing_field_renders_nothing() {
+    let tpl = Template::new("[{{missing}}]").unwrap();
+    let data = json!({ "name": "Ferris" });
+
+    assert_eq!(tpl.render(&data), "[]");
+}
@@ -0,0 +1,60 @@
+use ramhorns::Template;
+use std::collections::{BTreeMap, HashMap};
+
+#[test]
+fn renders_btreemap_as_key_value_section() {
+    let tpl = Template::new("{{#map}}{{key}}={{value}};{{/map}}").unwrap();
+    let mut map = BTreeMap::new();
+    map.insert("a".to_string(), 1);
+    map.insert("b".to_string(), 2);
+    let mut data = HashMap::new();
+    data.insert("map", map);
+
+    assert_eq!(tpl.render(&data), "a=1;b=2;");
+}
+
+#[test]
+fn renders_hashmap_as_key_value_section() {
+    let tpl = Template::new("{{#map}}{{key}}={{value}};{{/map}}").unwrap();
+    let mut map = HashMap::new();
+    map.insert("only".to_string(), 1);
+    let mut data = HashMap::new();
+    data.insert("map", map);
+
+    assert_eq!(tpl.render(&data), "only=1;");
+}
+
+#[test]
+fn empty_map_renders_nothing() {
+    let tpl = Template::new("{{#map}}{{key}}{{/map}}").unwrap();
+    let map: BTreeMap<String, i32> = BTreeMap::new();
+    let mut data = HashMap::new();
+    data.insert("map", map);
+
+    assert_eq!(tpl.render(&data), "");
+}
+
+#[test]
+fn field_lookup_by_name_is_unaffected() {
+    let tpl = Template::new("{{one}}").unwrap();
+    let mut map = BTreeMap::new();
+    map.insert("one".to_string(), 1);
+
+    assert_eq!(tpl.render(&map), "1");
+}
+
+// Documents a breaking change: before `render_section` was added to this
+// impl, `{{#map}}...{{/map}}` fell through to the default `render_section`,
+// which pushed the map itself as the section's context, so the body could
+// resolve the map's own fields directly. The body is now evaluated once per
+// entry against a `key`/`value`-only `Entry`, so this no longer resolves.
+#[test]
+fn section_body_no_longer_sees_the_map_as_its_own_context() {
+    let tpl = Template::new("{{#map}}{{existing_key}}{{/map}}").unwrap();
+    let mut map = BTreeMap::new();
+    map.insert("existing_key".to_string(), "value".to_string());
+    let mut data = HashMap::new();
+    data.insert("map", map);
+
+    assert_eq!(tpl.render(&data), "");
+}